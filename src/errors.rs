@@ -0,0 +1,80 @@
+//! Human-readable explanations for `Bolt12ParseError`.
+//!
+//! A raw `{:?}` dump of the error enum tells a user nothing actionable, so
+//! this maps each variant to a plain-language summary and a suggested fix.
+//! Callers pass in which layer failed (bech32 decoding vs. the semantic
+//! `Offer`/`InvoiceRequest`/`Bolt12Invoice` parse) so the message is precise
+//! about whether the encoding or the message contents are malformed.
+
+use lightning::offers::parse::Bolt12ParseError;
+
+pub struct ParseErrorExplanation {
+    pub summary: String,
+    pub suggestion: String,
+}
+
+impl std::fmt::Display for ParseErrorExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.summary, self.suggestion)
+    }
+}
+
+/// Explains a failure from the bech32 decoding layer (`from_bech32_str`):
+/// the HRP, continuation format, or bech32 character set. BOLT12 strings
+/// carry no bech32 checksum, so none is validated.
+pub fn explain_bech32_error(error: &Bolt12ParseError) -> ParseErrorExplanation {
+    explain(error, "bech32 encoding")
+}
+
+/// Explains a failure from the semantic layer: `Offer::from_str`,
+/// `InvoiceRequest::from_str`, or `Bolt12Invoice::from_str` parsing the TLV
+/// records into a typed message.
+pub fn explain_semantic_error(error: &Bolt12ParseError) -> ParseErrorExplanation {
+    explain(error, "message contents")
+}
+
+fn explain(error: &Bolt12ParseError, layer: &str) -> ParseErrorExplanation {
+    match error {
+        Bolt12ParseError::InvalidContinuation => ParseErrorExplanation {
+            summary: "The `+`-separated continuation parts are malformed.".to_string(),
+            suggestion: "Remove any whitespace inside each `+`-separated part; whitespace is only \
+                 allowed between parts."
+                .to_string(),
+        },
+        Bolt12ParseError::InvalidBech32Hrp => ParseErrorExplanation {
+            summary: "Unrecognized BOLT12 message prefix.".to_string(),
+            suggestion: "Expected `lno` (offer), `lnr` (invoice_request), or `lni` (invoice); \
+                         double-check what you pasted."
+                .to_string(),
+        },
+        Bolt12ParseError::Bech32(e) => ParseErrorExplanation {
+            summary: format!("The bech32 character set or string format is invalid: {:?}", e),
+            suggestion: "Check for typos, dropped characters, or a copy/paste error in the \
+                         pasted string."
+                .to_string(),
+        },
+        Bolt12ParseError::Decode(e) => ParseErrorExplanation {
+            summary: format!("A TLV record failed to decode: {:?}", e),
+            suggestion: format!(
+                "The {} may be truncated, or a record was written by an incompatible encoder.",
+                layer
+            ),
+        },
+        Bolt12ParseError::InvalidSemantics(e) => ParseErrorExplanation {
+            summary: format!("The {} violates BOLT12's semantic rules: {:?}", layer, e),
+            suggestion: "A required field is probably missing, or two mutually exclusive \
+                         fields were both set."
+                .to_string(),
+        },
+        Bolt12ParseError::InvalidSignature(e) => ParseErrorExplanation {
+            summary: format!("The signature over the {} doesn't verify: {:?}", layer, e),
+            suggestion: "The message may have been tampered with, truncated, or signed with \
+                         the wrong key."
+                .to_string(),
+        },
+        other => ParseErrorExplanation {
+            summary: format!("Failed to parse the {}: {:?}", layer, other),
+            suggestion: "Double-check the pasted string for typos or truncation.".to_string(),
+        },
+    }
+}
@@ -1,12 +1,20 @@
 use eframe::egui;
 use lightning::{
-    bitcoin::bech32::{NoChecksum, primitives::decode::CheckedHrpstring},
+    bitcoin::bech32::{primitives::decode::CheckedHrpstring, NoChecksum},
+    bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey},
     offers::{
-        offer::{Amount, Offer, Quantity},
+        invoice::Bolt12Invoice,
+        invoice_request::InvoiceRequest,
+        offer::{Amount, Offer, OfferBuilder, Quantity},
         parse::Bolt12ParseError,
     },
 };
+use qrcode::QrCode;
 use std::str::FromStr;
+use std::time::Duration;
+
+use crate::errors::{explain_bech32_error, explain_semantic_error};
+use crate::tlv::parse_tlv_stream;
 
 struct Theme {
     accent_color: egui::Color32,
@@ -30,7 +38,44 @@ impl Default for Theme {
     }
 }
 
-const BECH32_HRP: &'static str = "lno";
+// The three BOLT12 message types share the same bech32 continuation format and
+// are distinguished only by their human-readable part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bolt12MessageType {
+    Offer,
+    InvoiceRequest,
+    Invoice,
+}
+
+impl Bolt12MessageType {
+    const OFFER_HRP: &'static str = "lno";
+    const INVOICE_REQUEST_HRP: &'static str = "lnr";
+    const INVOICE_HRP: &'static str = "lni";
+
+    // Compare against the lowercase'd iter to allow for all-uppercase HRPs.
+    fn from_hrp(hrp: &lightning::bitcoin::bech32::Hrp) -> Result<Self, Bolt12ParseError> {
+        if hrp.lowercase_char_iter().eq(Self::OFFER_HRP.chars()) {
+            Ok(Bolt12MessageType::Offer)
+        } else if hrp
+            .lowercase_char_iter()
+            .eq(Self::INVOICE_REQUEST_HRP.chars())
+        {
+            Ok(Bolt12MessageType::InvoiceRequest)
+        } else if hrp.lowercase_char_iter().eq(Self::INVOICE_HRP.chars()) {
+            Ok(Bolt12MessageType::Invoice)
+        } else {
+            Err(Bolt12ParseError::InvalidBech32Hrp)
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Bolt12MessageType::Offer => "Offer",
+            Bolt12MessageType::InvoiceRequest => "Invoice Request",
+            Bolt12MessageType::Invoice => "Invoice",
+        }
+    }
+}
 
 // Used to avoid copying a bech32 string not containing the continuation character (+).
 enum Bech32String<'a> {
@@ -47,7 +92,7 @@ impl<'a> AsRef<str> for Bech32String<'a> {
     }
 }
 
-fn from_bech32_str(s: &str) -> Result<Vec<u8>, Bolt12ParseError> {
+fn from_bech32_str(s: &str) -> Result<(Bolt12MessageType, Vec<u8>), Bolt12ParseError> {
     // Offer encoding may be split by '+' followed by optional whitespace.
     let encoded = match s.split('+').skip(1).next() {
         Some(_) => {
@@ -68,21 +113,67 @@ fn from_bech32_str(s: &str) -> Result<Vec<u8>, Bolt12ParseError> {
     };
 
     let parsed = CheckedHrpstring::new::<NoChecksum>(encoded.as_ref())?;
-    let hrp = parsed.hrp();
-    // Compare the lowercase'd iter to allow for all-uppercase HRPs
-    if hrp.lowercase_char_iter().ne(BECH32_HRP.chars()) {
-        return Err(Bolt12ParseError::InvalidBech32Hrp);
-    }
+    let message_type = Bolt12MessageType::from_hrp(&parsed.hrp())?;
 
     let data = parsed.byte_iter().collect::<Vec<u8>>();
-    Ok(data)
+    Ok((message_type, data))
+}
+
+// Splits a canonical bech32 string into `+`-joined chunks, matching the
+// continuation format `from_bech32_str` accepts on input.
+fn chunk_continuation(s: &str, width: usize) -> String {
+    if width == 0 {
+        return s.to_string();
+    }
+    s.as_bytes()
+        .chunks(width)
+        .map(|chunk| std::str::from_utf8(chunk).expect("bech32 strings are ASCII"))
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+enum DecodedMessage {
+    Offer(Offer),
+    InvoiceRequest(InvoiceRequest),
+    Invoice(Bolt12Invoice),
+}
+
+#[derive(PartialEq, Eq)]
+enum AppMode {
+    Decode,
+    Build,
+}
+
+// Inputs for the offer construction form. Kept as plain strings so the form
+// can hold invalid intermediate input (e.g. a half-typed number) without
+// losing what the user typed.
+#[derive(Default)]
+struct BuildForm {
+    description: String,
+    use_currency: bool,
+    amount_sats: String,
+    currency_code: String,
+    currency_amount: String,
+    issuer: String,
+    quantity_max: String,
+    absolute_expiry_secs: String,
 }
 
 pub struct Bolt12OfferDecoderApp {
-    pub offer: Option<Offer>,
+    message: Option<DecodedMessage>,
     input_text: String,
     error_message: Option<String>,
     theme: Theme,
+    mode: AppMode,
+    build_form: BuildForm,
+    // A locally-generated signing key used only to preview offers built in
+    // this tool; it does not correspond to a real Lightning node identity.
+    signing_pubkey: PublicKey,
+    continuation_chunk_width: usize,
+    // Caches the last-rendered QR texture keyed by the string it encodes, so
+    // repaints don't re-run Reed-Solomon encoding and re-upload a GPU texture
+    // every frame while the QR panel is open.
+    qr_cache: Option<(String, egui::TextureHandle)>,
 }
 
 impl Default for Bolt12OfferDecoderApp {
@@ -90,18 +181,27 @@ impl Default for Bolt12OfferDecoderApp {
         let default_input_text = String::from(
             "lno1pqps7sjqpgt+yzm3qv4uxzmtsd3jjqer9wd3hy6tsw3+5k7msjzfpy7nz5yqcn+ygrfdej82um5wf5k2uckyypwa3eyt44h6txtxquqh7lz5djge4afgfjn7k4rgrkuag0jsd+5xvxg",
         );
+        let secp_ctx = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).expect("valid secret key");
+        let signing_pubkey = PublicKey::from_secret_key(&secp_ctx, &secret_key);
+
         Self {
-            offer: None,
+            message: None,
             input_text: default_input_text,
             error_message: None,
             theme: Theme::default(),
+            mode: AppMode::Decode,
+            build_form: BuildForm::default(),
+            signing_pubkey,
+            continuation_chunk_width: 50,
+            qr_cache: None,
         }
     }
 }
 
 impl eframe::App for Bolt12OfferDecoderApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        self.decode_offer();
+        self.decode_message();
 
         let mut style = (*ctx.style()).clone();
         style.visuals.dark_mode = true;
@@ -118,30 +218,54 @@ impl eframe::App for Bolt12OfferDecoderApp {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.add_space(20.0);
             self.display_title(ui);
-            self.display_text_field(ui);
+            self.display_mode_toggle(ui);
 
             ui.add_space(20.0);
-            match from_bech32_str(&self.input_text) {
-                Ok(data_part) => {
-                    let data_part_str = data_part
-                        .iter()
-                        .map(|d| format!("{:02x}", d))
-                        .collect::<Vec<_>>()
-                        .join("");
-                    let title = egui::RichText::new(format!("data part: {}", data_part_str))
-                        .size(12.0)
-                        .color(self.theme.secondary_accent_color)
-                        .strong();
-                    ui.label(title);
-                }
-                Err(_) => {}
-            }
-            if let Some(offer) = &self.offer {
-                self.display_offer(ui, offer);
-            }
+            match self.mode {
+                AppMode::Decode => {
+                    self.display_text_field(ui);
 
-            if let Some(error) = &self.error_message {
-                self.display_error(ui, error);
+                    ui.add_space(20.0);
+                    match from_bech32_str(&self.input_text) {
+                        Ok((message_type, data_part)) => {
+                            let data_part_str = data_part
+                                .iter()
+                                .map(|d| format!("{:02x}", d))
+                                .collect::<Vec<_>>()
+                                .join("");
+                            let title = egui::RichText::new(format!(
+                                "{} data part: {}",
+                                message_type.label(),
+                                data_part_str
+                            ))
+                            .size(12.0)
+                            .color(self.theme.secondary_accent_color)
+                            .strong();
+                            ui.label(title);
+                            self.display_tlv_inspector(ui, &data_part);
+                        }
+                        Err(_) => {}
+                    }
+                    match &self.message {
+                        Some(DecodedMessage::Offer(offer)) => {
+                            let offer = offer.clone();
+                            self.display_offer(ui, &offer);
+                            self.display_offer_export(ui, &offer);
+                        }
+                        Some(DecodedMessage::InvoiceRequest(invoice_request)) => {
+                            self.display_invoice_request(ui, invoice_request)
+                        }
+                        Some(DecodedMessage::Invoice(invoice)) => self.display_invoice(ui, invoice),
+                        None => {}
+                    }
+
+                    if let Some(error) = &self.error_message {
+                        self.display_error(ui, error);
+                    }
+                }
+                AppMode::Build => {
+                    self.display_build_form(ui);
+                }
             }
 
             ui.add_space(30.0);
@@ -151,23 +275,42 @@ impl eframe::App for Bolt12OfferDecoderApp {
 }
 
 impl Bolt12OfferDecoderApp {
-    fn decode_offer(&mut self) {
+    fn decode_message(&mut self) {
         let trimmed = self.input_text.trim();
 
         if trimmed.is_empty() {
             self.error_message = None;
-            self.offer = None;
+            self.message = None;
             return;
         }
 
-        match Offer::from_str(trimmed) {
-            Ok(decoder) => {
-                self.offer = Some(decoder);
+        let message_type = match from_bech32_str(trimmed) {
+            Ok((message_type, _)) => message_type,
+            Err(e) => {
+                self.error_message = Some(explain_bech32_error(&e).to_string());
+                self.message = None;
+                return;
+            }
+        };
+
+        let decoded = match message_type {
+            Bolt12MessageType::Offer => Offer::from_str(trimmed).map(DecodedMessage::Offer),
+            Bolt12MessageType::InvoiceRequest => {
+                InvoiceRequest::from_str(trimmed).map(DecodedMessage::InvoiceRequest)
+            }
+            Bolt12MessageType::Invoice => {
+                Bolt12Invoice::from_str(trimmed).map(DecodedMessage::Invoice)
+            }
+        };
+
+        match decoded {
+            Ok(message) => {
+                self.message = Some(message);
                 self.error_message = None;
             }
             Err(e) => {
-                self.error_message = Some(format!("Failed to parse offer: {:?}", e));
-                self.offer = None;
+                self.error_message = Some(explain_semantic_error(&e).to_string());
+                self.message = None;
             }
         }
     }
@@ -207,6 +350,36 @@ impl Bolt12OfferDecoderApp {
         });
     }
 
+    fn display_tlv_inspector(&self, ui: &mut egui::Ui, data: &[u8]) {
+        egui::CollapsingHeader::new(
+            egui::RichText::new("üß¨ Raw TLV Stream")
+                .color(self.theme.accent_color)
+                .strong(),
+        )
+        .default_open(false)
+        .show(ui, |ui| match parse_tlv_stream(data) {
+            Ok(records) => {
+                if records.is_empty() {
+                    ui.label(egui::RichText::new("No TLV records").color(self.theme.text_color));
+                }
+                for record in &records {
+                    self.display_offer_field(
+                        ui,
+                        &record.label(),
+                        record.value_hex(),
+                        self.theme.text_color_secondary,
+                    );
+                }
+            }
+            Err(e) => {
+                ui.label(
+                    egui::RichText::new(format!("Failed to parse TLV stream: {}", e))
+                        .color(egui::Color32::from_rgb(255, 150, 150)),
+                );
+            }
+        });
+    }
+
     fn display_error(&self, ui: &mut egui::Ui, error: &str) {
         egui::Frame::new()
             .fill(egui::Color32::from_rgb(60, 25, 25))
@@ -273,6 +446,175 @@ impl Bolt12OfferDecoderApp {
         });
     }
 
+    fn display_mode_toggle(&mut self, ui: &mut egui::Ui) {
+        ui.vertical_centered(|ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .selectable_label(self.mode == AppMode::Decode, "üîç Decode")
+                    .clicked()
+                {
+                    self.mode = AppMode::Decode;
+                }
+                if ui
+                    .selectable_label(self.mode == AppMode::Build, "üõ† Build")
+                    .clicked()
+                {
+                    self.mode = AppMode::Build;
+                }
+            });
+        });
+    }
+
+    fn build_offer(&self) -> Result<Offer, String> {
+        let mut builder = OfferBuilder::new(self.signing_pubkey);
+
+        if !self.build_form.description.is_empty() {
+            builder = builder.description(self.build_form.description.clone());
+        }
+
+        if self.build_form.use_currency {
+            let code_bytes = self.build_form.currency_code.trim().as_bytes();
+            if code_bytes.len() != 3 {
+                return Err("currency code must be a 3-letter ISO-4217 code".to_string());
+            }
+            let mut iso4217_code = [0u8; 3];
+            iso4217_code.copy_from_slice(code_bytes);
+            let amount = self
+                .build_form
+                .currency_amount
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| "currency amount must be a whole number".to_string())?;
+            builder = builder.amount(Amount::Currency {
+                iso4217_code,
+                amount,
+            });
+        } else if !self.build_form.amount_sats.trim().is_empty() {
+            let amount_sats = self
+                .build_form
+                .amount_sats
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| "amount must be a whole number of sats".to_string())?;
+            builder = builder.amount(Amount::Bitcoin {
+                amount_msats: amount_sats * 1000,
+            });
+        }
+
+        if !self.build_form.issuer.is_empty() {
+            builder = builder.issuer(self.build_form.issuer.clone());
+        }
+
+        if !self.build_form.quantity_max.trim().is_empty() {
+            let max = self
+                .build_form
+                .quantity_max
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| "supported quantity must be a whole number".to_string())?;
+            builder = builder.supported_quantity(Quantity::Bounded(max));
+        }
+
+        if !self.build_form.absolute_expiry_secs.trim().is_empty() {
+            let secs = self
+                .build_form
+                .absolute_expiry_secs
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| "absolute expiry must be a unix timestamp in seconds".to_string())?;
+            builder = builder.absolute_expiry(Duration::from_secs(secs));
+        }
+
+        builder
+            .build()
+            .map_err(|e| format!("Failed to build offer: {:?}", e))
+    }
+
+    fn display_build_form(&mut self, ui: &mut egui::Ui) {
+        egui::Frame::new()
+            .fill(self.theme.card_bg)
+            .corner_radius(egui::CornerRadius::same(12))
+            .inner_margin(egui::Margin::same(20))
+            .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(60, 60, 80)))
+            .show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new("üõ† Build a BOLT12 Offer")
+                        .size(16.0)
+                        .color(self.theme.accent_color)
+                        .strong(),
+                );
+                ui.label(
+                    egui::RichText::new(
+                        "‚ö† Signed with a local demo key, not a real node identity \u{2014} \
+                         do not use this offer to actually receive funds.",
+                    )
+                    .size(12.0)
+                    .color(egui::Color32::from_rgb(255, 150, 150))
+                    .italics(),
+                );
+                ui.add_space(8.0);
+
+                ui.label("Description");
+                ui.text_edit_singleline(&mut self.build_form.description);
+
+                ui.add_space(6.0);
+                ui.checkbox(&mut self.build_form.use_currency, "Use ISO-4217 currency");
+                if self.build_form.use_currency {
+                    ui.horizontal(|ui| {
+                        ui.label("Currency code");
+                        ui.text_edit_singleline(&mut self.build_form.currency_code);
+                        ui.label("Amount");
+                        ui.text_edit_singleline(&mut self.build_form.currency_amount);
+                    });
+                } else {
+                    ui.label("Amount (sats)");
+                    ui.text_edit_singleline(&mut self.build_form.amount_sats);
+                }
+
+                ui.add_space(6.0);
+                ui.label("Issuer");
+                ui.text_edit_singleline(&mut self.build_form.issuer);
+
+                ui.add_space(6.0);
+                ui.label("Supported quantity (max)");
+                ui.text_edit_singleline(&mut self.build_form.quantity_max);
+
+                ui.add_space(6.0);
+                ui.label("Absolute expiry (unix timestamp, seconds)");
+                ui.text_edit_singleline(&mut self.build_form.absolute_expiry_secs);
+            });
+
+        ui.add_space(15.0);
+
+        match self.build_offer() {
+            Ok(offer) => {
+                let encoded = offer.to_string();
+
+                ui.horizontal(|ui| {
+                    let title = egui::RichText::new(format!("built offer: {}", encoded))
+                        .size(12.0)
+                        .color(self.theme.secondary_accent_color)
+                        .strong();
+                    ui.label(title);
+                    if ui.button("üìã Copy").clicked() {
+                        ui.ctx().copy_text(encoded.clone());
+                    }
+                });
+
+                // Round-trip through the decode path so the preview below
+                // reflects exactly what a recipient would see.
+                match Offer::from_str(&encoded) {
+                    Ok(reparsed) => self.display_offer(ui, &reparsed),
+                    Err(e) => self
+                        .display_error(ui, &format!("Built offer failed to round-trip: {:?}", e)),
+                }
+            }
+            Err(e) => {
+                self.display_error(ui, &e);
+            }
+        }
+    }
+
     fn display_footer(&self, ui: &mut egui::Ui) {
         ui.vertical_centered(|ui| {
             ui.add_space(30.0);
@@ -285,7 +627,7 @@ impl Bolt12OfferDecoderApp {
         });
     }
 
-    fn display_offer(&self, ui: &mut egui::Ui, offer: &Offer) {
+    fn display_offer(&mut self, ui: &mut egui::Ui, offer: &Offer) {
         let max_width = 600.0;
         ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
             ui.set_max_width(max_width);
@@ -411,6 +753,294 @@ impl Bolt12OfferDecoderApp {
                                 },
                                 self.theme.secondary_accent_color,
                             );
+
+                            self.display_offer_qr_code(ui, offer);
+                        });
+                });
+        });
+    }
+
+    fn display_offer_qr_code(&mut self, ui: &mut egui::Ui, offer: &Offer) {
+        egui::CollapsingHeader::new(
+            egui::RichText::new("üì± QR Code")
+                .color(self.theme.accent_color)
+                .strong(),
+        )
+        .default_open(false)
+        .show(ui, |ui| {
+            // Uppercase bech32 is more compact in QR alphanumeric mode; BOLT12
+            // HRP comparison already tolerates all-uppercase encodings.
+            let encoded = offer.to_string().to_uppercase();
+            let ctx = ui.ctx().clone();
+            match self.render_qr_texture(&ctx, &encoded) {
+                Some(texture) => {
+                    ui.add(egui::Image::new(&texture).fit_to_exact_size(egui::vec2(220.0, 220.0)));
+                }
+                None => {
+                    ui.label(
+                        egui::RichText::new("Failed to render QR code")
+                            .color(egui::Color32::from_rgb(255, 150, 150)),
+                    );
+                }
+            }
+        });
+    }
+
+    fn render_qr_texture(
+        &mut self,
+        ctx: &egui::Context,
+        data: &str,
+    ) -> Option<egui::TextureHandle> {
+        if let Some((cached_data, texture)) = &self.qr_cache {
+            if cached_data == data {
+                return Some(texture.clone());
+            }
+        }
+
+        let code = QrCode::new(data).ok()?;
+        let image = code.render::<image::Luma<u8>>().quiet_zone(true).build();
+        let (width, height) = image.dimensions();
+        let pixels: Vec<egui::Color32> = image
+            .pixels()
+            .map(|pixel| egui::Color32::from_gray(pixel.0[0]))
+            .collect();
+        let color_image = egui::ColorImage {
+            size: [width as usize, height as usize],
+            pixels,
+        };
+        let texture = ctx.load_texture("offer-qr-code", color_image, egui::TextureOptions::NEAREST);
+        self.qr_cache = Some((data.to_string(), texture.clone()));
+        Some(texture)
+    }
+
+    fn display_offer_export(&mut self, ui: &mut egui::Ui, offer: &Offer) {
+        let max_width = 600.0;
+        ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+            ui.set_max_width(max_width);
+            egui::CollapsingHeader::new(
+                egui::RichText::new("üìî Re-encode & Export")
+                    .color(self.theme.accent_color)
+                    .strong(),
+            )
+            .default_open(false)
+            .show(ui, |ui| {
+                let canonical = offer.to_string();
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new("Compact")
+                            .color(self.theme.text_color_secondary)
+                            .strong(),
+                    );
+                    if ui.button("üìã Copy").clicked() {
+                        ui.ctx().copy_text(canonical.clone());
+                    }
+                });
+                ui.add(
+                    egui::TextEdit::multiline(&mut canonical.clone())
+                        .desired_rows(2)
+                        .font(egui::TextStyle::Monospace)
+                        .interactive(false),
+                );
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    ui.label("Continuation chunk width:");
+                    ui.add(egui::Slider::new(
+                        &mut self.continuation_chunk_width,
+                        8..=200,
+                    ));
+                });
+
+                let chunked = chunk_continuation(&canonical, self.continuation_chunk_width);
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new("Continuation-formatted")
+                            .color(self.theme.text_color_secondary)
+                            .strong(),
+                    );
+                    if ui.button("üìã Copy").clicked() {
+                        ui.ctx().copy_text(chunked.clone());
+                    }
+                });
+                ui.add(
+                    egui::TextEdit::multiline(&mut chunked.clone())
+                        .desired_rows(4)
+                        .font(egui::TextStyle::Monospace)
+                        .interactive(false),
+                );
+
+                ui.add_space(10.0);
+                let round_trip_ok = Offer::from_str(&chunked)
+                    .map(|reparsed| reparsed.to_string() == canonical)
+                    .unwrap_or(false);
+                if round_trip_ok {
+                    ui.label(
+                        egui::RichText::new("‚úÖ Round-trip verified: re-parses to the same offer")
+                            .color(egui::Color32::from_rgb(120, 220, 120)),
+                    );
+                } else {
+                    ui.label(
+                        egui::RichText::new(
+                            "‚ùå Round-trip failed: the continuation-formatted string did not re-parse to the same offer",
+                        )
+                        .color(egui::Color32::from_rgb(255, 150, 150)),
+                    );
+                }
+            });
+        });
+    }
+
+    fn display_invoice_request(&self, ui: &mut egui::Ui, invoice_request: &InvoiceRequest) {
+        let max_width = 600.0;
+        ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+            ui.set_max_width(max_width);
+            egui::Frame::new()
+                .fill(self.theme.card_bg)
+                .corner_radius(egui::CornerRadius::same(12))
+                .inner_margin(egui::Margin::same(20))
+                .stroke(egui::Stroke::new(2.0, self.theme.accent_color))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new("‚ö° Decoded Invoice Request Information")
+                                .size(20.0)
+                                .color(self.theme.secondary_accent_color)
+                                .strong(),
+                        );
+                    });
+
+                    ui.add_space(15.0);
+
+                    egui::ScrollArea::vertical()
+                        .max_height(500.0)
+                        .show(ui, |ui| {
+                            self.display_offer_field(
+                                ui,
+                                "‚õì Chain",
+                                format!("{:?}", invoice_request.chain()),
+                                self.theme.text_color_secondary,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üí∞ Amount",
+                                if let Some(amount_msats) = invoice_request.amount_msats() {
+                                    format!("{} msats", amount_msats)
+                                } else {
+                                    "Any amount".to_string()
+                                },
+                                self.theme.secondary_accent_color,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üéØ Features",
+                                format!("{:?}", invoice_request.invoice_request_features()),
+                                self.theme.accent_color,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üëõ Payer Id",
+                                format!("{}", invoice_request.payer_signing_pubkey()),
+                                self.theme.text_color_secondary,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üìä Quantity",
+                                if let Some(quantity) = invoice_request.quantity() {
+                                    format!("{}", quantity)
+                                } else {
+                                    "Not specified".to_string()
+                                },
+                                self.theme.accent_color,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üóí Payer Note",
+                                if let Some(note) = invoice_request.payer_note() {
+                                    note.to_string()
+                                } else {
+                                    "Not specified".to_string()
+                                },
+                                self.theme.text_color_secondary,
+                            );
+                        });
+                });
+        });
+    }
+
+    fn display_invoice(&self, ui: &mut egui::Ui, invoice: &Bolt12Invoice) {
+        let max_width = 600.0;
+        ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
+            ui.set_max_width(max_width);
+            egui::Frame::new()
+                .fill(self.theme.card_bg)
+                .corner_radius(egui::CornerRadius::same(12))
+                .inner_margin(egui::Margin::same(20))
+                .stroke(egui::Stroke::new(2.0, self.theme.accent_color))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new("‚ö° Decoded Invoice Information")
+                                .size(20.0)
+                                .color(self.theme.secondary_accent_color)
+                                .strong(),
+                        );
+                    });
+
+                    ui.add_space(15.0);
+
+                    egui::ScrollArea::vertical()
+                        .max_height(500.0)
+                        .show(ui, |ui| {
+                            self.display_offer_field(
+                                ui,
+                                "üìù Description",
+                                invoice
+                                    .description()
+                                    .map(|d| d.to_string())
+                                    .unwrap_or_else(|| "No description".to_string()),
+                                self.theme.accent_color,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üí∞ Amount",
+                                format!("{} msats", invoice.amount_msats()),
+                                self.theme.secondary_accent_color,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üïí Created At",
+                                format!("{:?}", invoice.created_at()),
+                                self.theme.text_color_secondary,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "‚è≥ Relative Expiry",
+                                format!("{:?}", invoice.relative_expiry()),
+                                self.theme.secondary_accent_color,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "#Ô∏è‚É£ Payment Hash",
+                                format!("{}", invoice.payment_hash()),
+                                self.theme.text_color_secondary,
+                            );
+
+                            self.display_offer_field(
+                                ui,
+                                "üóù Signing Pubkey",
+                                format!("{}", invoice.signing_pubkey()),
+                                self.theme.accent_color,
+                            );
                         });
                 });
         });
@@ -1,4 +1,6 @@
+pub mod errors;
 pub mod gui;
+pub mod tlv;
 
 #[cfg(target_arch = "wasm32")]
 use eframe::web_sys;
@@ -0,0 +1,186 @@
+//! Low-level TLV stream parsing shared by the GUI's raw inspector panel.
+//!
+//! BOLT12 messages are bech32-decoded into a flat byte stream that is itself a
+//! sequence of TLV records, each encoded with a `BigSize` type, a `BigSize`
+//! length, and `length` value bytes. This is a thin, display-oriented parser:
+//! it does not interpret record values beyond labelling a handful of known
+//! offer record types, leaving everything else as raw hex so unknown or
+//! experimental records stay visible instead of being dropped.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlvRecord {
+    pub record_type: u64,
+    pub value: Vec<u8>,
+}
+
+impl TlvRecord {
+    pub fn label(&self) -> String {
+        match known_offer_type_name(self.record_type) {
+            Some(name) => format!("type {} ({})", self.record_type, name),
+            None => format!("type {} (unknown)", self.record_type),
+        }
+    }
+
+    pub fn value_hex(&self) -> String {
+        self.value.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
+/// Record types from the BOLT12 `offer` TLV stream that this tool can label.
+fn known_offer_type_name(record_type: u64) -> Option<&'static str> {
+    match record_type {
+        2 => Some("chains"),
+        4 => Some("metadata"),
+        6 => Some("currency"),
+        8 => Some("amount"),
+        10 => Some("description"),
+        12 => Some("features"),
+        14 => Some("absolute_expiry"),
+        16 => Some("paths"),
+        18 => Some("issuer"),
+        20 => Some("quantity_max"),
+        22 => Some("issuer_id"),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TlvParseError {
+    UnexpectedEof,
+    TypesNotStrictlyIncreasing { previous: u64, found: u64 },
+}
+
+impl std::fmt::Display for TlvParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TlvParseError::UnexpectedEof => {
+                write!(f, "TLV stream ended in the middle of a record")
+            }
+            TlvParseError::TypesNotStrictlyIncreasing { previous, found } => write!(
+                f,
+                "record type {} did not strictly increase after {}",
+                found, previous
+            ),
+        }
+    }
+}
+
+/// Reads a single BigSize-encoded integer starting at `offset`, returning the
+/// value and the number of bytes consumed.
+fn read_bigsize(data: &[u8], offset: usize) -> Result<(u64, usize), TlvParseError> {
+    let first = *data.get(offset).ok_or(TlvParseError::UnexpectedEof)?;
+    let read_be = |width: usize| -> Result<&[u8], TlvParseError> {
+        let start = offset.checked_add(1).ok_or(TlvParseError::UnexpectedEof)?;
+        let end = start
+            .checked_add(width)
+            .ok_or(TlvParseError::UnexpectedEof)?;
+        data.get(start..end).ok_or(TlvParseError::UnexpectedEof)
+    };
+    match first {
+        0xfd => {
+            let bytes = read_be(2)?;
+            Ok((u16::from_be_bytes(bytes.try_into().unwrap()) as u64, 3))
+        }
+        0xfe => {
+            let bytes = read_be(4)?;
+            Ok((u32::from_be_bytes(bytes.try_into().unwrap()) as u64, 5))
+        }
+        0xff => {
+            let bytes = read_be(8)?;
+            Ok((u64::from_be_bytes(bytes.try_into().unwrap()), 9))
+        }
+        b => Ok((b as u64, 1)),
+    }
+}
+
+/// Walks a decoded BOLT12 data part as a TLV record list, enforcing that
+/// record types strictly increase as required by BOLT1's TLV format.
+pub fn parse_tlv_stream(data: &[u8]) -> Result<Vec<TlvRecord>, TlvParseError> {
+    let mut records = Vec::new();
+    let mut offset = 0;
+    let mut previous_type: Option<u64> = None;
+
+    while offset < data.len() {
+        let (record_type, type_len) = read_bigsize(data, offset)?;
+        offset = offset
+            .checked_add(type_len)
+            .ok_or(TlvParseError::UnexpectedEof)?;
+
+        if let Some(previous) = previous_type {
+            if record_type <= previous {
+                return Err(TlvParseError::TypesNotStrictlyIncreasing {
+                    previous,
+                    found: record_type,
+                });
+            }
+        }
+        previous_type = Some(record_type);
+
+        let (length, length_len) = read_bigsize(data, offset)?;
+        offset = offset
+            .checked_add(length_len)
+            .ok_or(TlvParseError::UnexpectedEof)?;
+
+        // `length` comes directly from an attacker/user-controlled BigSize
+        // field and may be as large as `u64::MAX`, so guard the addition
+        // instead of trusting it fits in a `usize` slice index.
+        let length = usize::try_from(length).map_err(|_| TlvParseError::UnexpectedEof)?;
+        let value_end = offset
+            .checked_add(length)
+            .ok_or(TlvParseError::UnexpectedEof)?;
+        let value = data
+            .get(offset..value_end)
+            .ok_or(TlvParseError::UnexpectedEof)?
+            .to_vec();
+        offset = value_end;
+
+        records.push(TlvRecord { record_type, value });
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_record_with_a_multi_byte_bigsize_length() {
+        let mut data = vec![2, 0xfd, 0x01, 0x2c]; // type 2, BigSize length 300
+        data.extend(std::iter::repeat(0xab).take(300));
+
+        let records = parse_tlv_stream(&data).unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].record_type, 2);
+        assert_eq!(records[0].value.len(), 300);
+        assert!(records[0].value.iter().all(|b| *b == 0xab));
+    }
+
+    #[test]
+    fn rejects_a_record_truncated_mid_bigsize() {
+        // Type 4, then a length BigSize that claims a 2-byte encoding but
+        // only has one byte left in the stream.
+        let data = vec![4, 0xfd, 0x00];
+
+        let err = parse_tlv_stream(&data).unwrap_err();
+
+        assert_eq!(err, TlvParseError::UnexpectedEof);
+    }
+
+    #[test]
+    fn rejects_a_non_increasing_type_sequence() {
+        // type 2, length 0, then type 1, length 0 - types must strictly increase.
+        let data = vec![2, 0, 1, 0];
+
+        let err = parse_tlv_stream(&data).unwrap_err();
+
+        assert_eq!(
+            err,
+            TlvParseError::TypesNotStrictlyIncreasing {
+                previous: 2,
+                found: 1,
+            }
+        );
+    }
+}